@@ -3,6 +3,7 @@ use std::fmt::Write;
 use arrow::temporal_conversions::{
     timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
 };
+use chrono::{Datelike, Timelike};
 #[cfg(feature = "timezones")]
 use polars_arrow::kernels::cast_timezone;
 
@@ -11,6 +12,127 @@ use super::*;
 use crate::prelude::DataType::Datetime;
 use crate::prelude::*;
 
+/// Controls how many digits of sub-second precision [`DatetimeChunked::to_rfc3339`] emits.
+///
+/// Mirrors [`chrono::SecondsFormat`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecondsFormat {
+    /// Only seconds, no fractional part.
+    Secs,
+    /// Millisecond (3 digit) precision.
+    Millis,
+    /// Microsecond (6 digit) precision.
+    Micros,
+    /// Nanosecond (9 digit) precision.
+    Nanos,
+    /// Automatically choose 0/3/6/9 digits, whichever is the shortest that round-trips exactly.
+    AutoSi,
+}
+
+/// Policy for resolving a local wall-clock time that [`DatetimeChunked::tz_localize`] cannot map
+/// to a single UTC instant.
+///
+/// An hour is ambiguous when clocks are set back for DST (it occurs twice); it is nonexistent
+/// when clocks are set forward (the "spring forward" gap). `Raise` applies to both cases; the
+/// other two also control which side of a gap to shift a nonexistent time onto.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ambiguous {
+    /// Use the earlier of the two possible UTC offsets.
+    Earliest,
+    /// Use the later of the two possible UTC offsets.
+    Latest,
+    /// Return a `ComputeError` instead of guessing.
+    Raise,
+}
+
+/// If `fmt` parses an explicit UTC offset, returns the format string that
+/// [`chrono::DateTime::parse_from_str`] can actually resolve an offset from; otherwise `None`.
+///
+/// `fmt`'s own `%z`/`%:z` specifiers are passed through unchanged, but a literal trailing `Z` (as
+/// in RFC 3339) is rewritten to `%#z`, since `DateTime::parse_from_str` cannot resolve an offset
+/// from a literal `Z` character — only `%#z` accepts the bare `Z` that real RFC 3339 timestamps
+/// (including this module's own [`to_rfc3339`][DatetimeChunked::to_rfc3339] output) use for UTC.
+/// A bare `%Z` (time zone *abbreviation*, e.g. `CEST`) doesn't count as an offset token either
+/// way — it carries no resolvable offset, so formats using only `%Z` fall through to the
+/// tz-naive parse path instead.
+fn fmt_as_offset_parse_fmt(fmt: &str) -> Option<String> {
+    if !fmt.as_bytes().contains(&b'Z') {
+        return (fmt.contains("%z") || fmt.contains("%:z")).then(|| fmt.to_string());
+    }
+
+    let bytes = fmt.as_bytes();
+    let mut rewritten = String::with_capacity(fmt.len() + 2);
+    let mut found_literal_z = false;
+    for (i, &b) in bytes.iter().enumerate() {
+        if b == b'Z' && (i == 0 || bytes[i - 1] != b'%') {
+            rewritten.push_str("%#z");
+            found_literal_z = true;
+        } else {
+            rewritten.push(b as char);
+        }
+    }
+    (found_literal_z || fmt.contains("%z") || fmt.contains("%:z")).then(|| rewritten)
+}
+
+fn fmt_fractional_digits(seconds_format: SecondsFormat, nanos: u32) -> u8 {
+    match seconds_format {
+        SecondsFormat::Secs => 0,
+        SecondsFormat::Millis => 3,
+        SecondsFormat::Micros => 6,
+        SecondsFormat::Nanos => 9,
+        SecondsFormat::AutoSi => {
+            if nanos == 0 {
+                0
+            } else if nanos % 1_000_000 == 0 {
+                3
+            } else if nanos % 1_000 == 0 {
+                6
+            } else {
+                9
+            }
+        }
+    }
+}
+
+fn write_rfc3339_local(buf: &mut String, ndt: NaiveDateTime, seconds_format: SecondsFormat) {
+    write!(buf, "{}", ndt.format("%Y-%m-%dT%H:%M:%S")).unwrap();
+    match fmt_fractional_digits(seconds_format, ndt.nanosecond()) {
+        0 => {}
+        3 => write!(buf, ".{:03}", ndt.nanosecond() / 1_000_000).unwrap(),
+        6 => write!(buf, ".{:06}", ndt.nanosecond() / 1_000).unwrap(),
+        9 => write!(buf, ".{:09}", ndt.nanosecond()).unwrap(),
+        _ => unreachable!(),
+    }
+}
+
+/// Formats `ndt` (a UTC instant) as it reads on the wall clock in `tz`, followed by `tz`'s signed
+/// offset at that instant, e.g. `2021-06-15T08:00:00-04:00`.
+#[cfg(feature = "timezones")]
+fn write_rfc3339_offset(
+    buf: &mut String,
+    ndt: NaiveDateTime,
+    tz: &str,
+    seconds_format: SecondsFormat,
+) -> PolarsResult<()> {
+    use arrow::temporal_conversions::parse_offset;
+    use chrono::TimeZone as _;
+    use chrono_tz::Tz;
+
+    let offset = match parse_offset(tz) {
+        Ok(offset) => offset,
+        Err(_) => {
+            let tz: Tz = tz.parse().map_err(|_| {
+                PolarsError::ComputeError(format!("Could not parse timezone: '{tz}'").into())
+            })?;
+            tz.offset_from_utc_datetime(&ndt).fix()
+        }
+    };
+    let local = offset.from_utc_datetime(&ndt).naive_local();
+    write_rfc3339_local(buf, local, seconds_format);
+    write!(buf, "{offset}").unwrap();
+    Ok(())
+}
+
 #[cfg(feature = "timezones")]
 fn validate_time_zone(tz: TimeZone) -> PolarsResult<()> {
     use arrow::temporal_conversions::parse_offset;
@@ -131,7 +253,16 @@ impl DatetimeChunked {
     }
 
     /// Format Datetime with a `fmt` rule. See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// When the array carries a [`time_zone`][Self::time_zone], each value is resolved in that
+    /// zone before formatting, so offset tokens (`%z`, `%:z`) and the abbreviation (`%Z`) reflect
+    /// the stored zone rather than UTC.
     pub fn strftime(&self, fmt: &str) -> Utf8Chunked {
+        #[cfg(feature = "timezones")]
+        if let Some(tz) = self.time_zone() {
+            return self.strftime_in_time_zone(&tz.clone(), fmt);
+        }
+
         let conversion_f = match self.time_unit() {
             TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
             TimeUnit::Microseconds => timestamp_us_to_datetime,
@@ -144,14 +275,7 @@ impl DatetimeChunked {
             .unwrap();
         let fmted = format!("{}", dt.format(fmt));
 
-        #[allow(unused_mut)]
-        let mut ca = self.clone();
-        #[cfg(feature = "timezones")]
-        if self.time_zone().is_some() {
-            ca = ca.cast_time_zone(Some("UTC")).unwrap();
-        }
-
-        let mut ca: Utf8Chunked = ca.apply_kernel_cast(&|arr| {
+        let mut ca: Utf8Chunked = self.apply_kernel_cast(&|arr| {
             let mut buf = String::new();
             let mut mutarr =
                 MutableUtf8Array::with_capacities(arr.len(), arr.len() * fmted.len() + 1);
@@ -175,6 +299,114 @@ impl DatetimeChunked {
         ca
     }
 
+    /// `strftime` for a tz-aware array: resolves each timestamp in `tz` via chrono_tz (or a fixed
+    /// offset) before formatting, instead of the UTC fast path used for tz-naive/UTC data.
+    #[cfg(feature = "timezones")]
+    fn strftime_in_time_zone(&self, tz: &str, fmt: &str) -> Utf8Chunked {
+        use arrow::temporal_conversions::parse_offset;
+        use chrono::TimeZone as _;
+        use chrono_tz::Tz;
+
+        enum ResolvedTz {
+            Offset(chrono::FixedOffset),
+            Named(Tz),
+        }
+        let resolved = match parse_offset(tz) {
+            Ok(offset) => ResolvedTz::Offset(offset),
+            Err(_) => ResolvedTz::Named(tz.parse::<Tz>().unwrap()),
+        };
+
+        let conversion_f = match self.time_unit() {
+            TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+            TimeUnit::Microseconds => timestamp_us_to_datetime,
+            TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+        };
+
+        let mut ca: Utf8Chunked = self.apply_kernel_cast(&|arr| {
+            let mut buf = String::new();
+            let mut mutarr = MutableUtf8Array::with_capacities(arr.len(), arr.len() * 32 + 1);
+
+            for opt in arr.into_iter() {
+                match opt {
+                    None => mutarr.push_null(),
+                    Some(v) => {
+                        buf.clear();
+                        let ndt = conversion_f(*v);
+                        match &resolved {
+                            ResolvedTz::Offset(offset) => {
+                                write!(buf, "{}", offset.from_utc_datetime(&ndt).format(fmt))
+                                    .unwrap()
+                            }
+                            ResolvedTz::Named(tz) => {
+                                write!(buf, "{}", tz.from_utc_datetime(&ndt).format(fmt)).unwrap()
+                            }
+                        }
+                        mutarr.push(Some(&buf))
+                    }
+                }
+            }
+
+            let arr: Utf8Array<i64> = mutarr.into();
+            Box::new(arr)
+        });
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Format as RFC 3339 / ISO 8601, e.g. `2001-01-01T00:00:00.123456Z`.
+    ///
+    /// Unlike [`strftime`][Self::strftime], which always formats in UTC, this renders each
+    /// value in its stored time zone (or with a `Z` suffix for UTC/tz-naive data), so the
+    /// output round-trips through [`strptime`][Self::strptime] when given a matching `fmt`
+    /// (e.g. `"%Y-%m-%dT%H:%M:%SZ"` for the `Z`-suffixed case).
+    pub fn to_rfc3339(&self, seconds_format: SecondsFormat) -> Utf8Chunked {
+        let conversion_f = match self.time_unit() {
+            TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+            TimeUnit::Microseconds => timestamp_us_to_datetime,
+            TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+        };
+        #[cfg(feature = "timezones")]
+        let tz = self.time_zone().clone();
+
+        let mut ca: Utf8Chunked = self.apply_kernel_cast(&|arr| {
+            let mut buf = String::new();
+            let mut mutarr = MutableUtf8Array::with_capacities(arr.len(), arr.len() * 32 + 1);
+
+            for opt in arr.into_iter() {
+                match opt {
+                    None => mutarr.push_null(),
+                    Some(v) => {
+                        buf.clear();
+                        let ndt = conversion_f(*v);
+
+                        #[cfg(feature = "timezones")]
+                        match &tz {
+                            Some(tz) if tz != "UTC" => {
+                                write_rfc3339_offset(&mut buf, ndt, tz, seconds_format).unwrap()
+                            }
+                            _ => {
+                                write_rfc3339_local(&mut buf, ndt, seconds_format);
+                                buf.push('Z');
+                            }
+                        }
+                        #[cfg(not(feature = "timezones"))]
+                        {
+                            write_rfc3339_local(&mut buf, ndt, seconds_format);
+                            buf.push('Z');
+                        }
+
+                        mutarr.push(Some(&buf))
+                    }
+                }
+            }
+
+            let arr: Utf8Array<i64> = mutarr.into();
+            Box::new(arr)
+        });
+        ca.rename(self.name());
+        ca
+    }
+
     /// Construct a new [`DatetimeChunked`] from an iterator over [`NaiveDateTime`].
     pub fn from_naive_datetime<I: IntoIterator<Item = NaiveDateTime>>(
         name: &str,
@@ -204,6 +436,69 @@ impl DatetimeChunked {
         Int64Chunked::from_iter_options(name, vals).into_datetime(tu, None)
     }
 
+    /// Parse a [`Utf8Chunked`] into a [`DatetimeChunked`], the inverse of
+    /// [`strftime`][Self::strftime].
+    ///
+    /// When `fmt` contains an offset token (`%z`/`%:z`) or a literal trailing `Z` (RFC 3339), each
+    /// row is parsed with [`DateTime::parse_from_str`] (rewriting a literal `Z` to `%#z` first, the
+    /// only chrono specifier that accepts a bare `Z`) and normalized to its UTC instant, with the
+    /// datatype's time zone set to `tz` (defaulting to `"UTC"`). Otherwise — including when `fmt`
+    /// only has a `%Z` abbreviation, which carries no resolvable offset — rows are parsed with
+    /// [`NaiveDateTime::parse_from_str`] and produce tz-naive values. Rows that don't match `fmt`
+    /// become null, unless `strict` is set, in which case the first unparseable row returns a
+    /// `ComputeError` naming the offending string.
+    pub fn strptime(
+        name: &str,
+        ca: &Utf8Chunked,
+        fmt: &str,
+        tu: TimeUnit,
+        tz: Option<TimeZone>,
+        strict: bool,
+    ) -> PolarsResult<DatetimeChunked> {
+        use chrono::DateTime;
+
+        #[cfg(feature = "timezones")]
+        if let Some(tz) = &tz {
+            validate_time_zone(tz.clone())?;
+        }
+
+        let offset_fmt = fmt_as_offset_parse_fmt(fmt);
+        let to_i64 = match tu {
+            TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+            TimeUnit::Microseconds => datetime_to_timestamp_us,
+            TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+        };
+
+        let mut out = Vec::with_capacity(ca.len());
+        for opt_s in ca.into_iter() {
+            match opt_s {
+                None => out.push(None),
+                Some(s) => {
+                    let ndt = if let Some(offset_fmt) = &offset_fmt {
+                        DateTime::parse_from_str(s, offset_fmt)
+                            .ok()
+                            .map(|dt| dt.naive_utc())
+                    } else {
+                        NaiveDateTime::parse_from_str(s, fmt).ok()
+                    };
+                    match ndt {
+                        Some(ndt) => out.push(Some(to_i64(ndt))),
+                        None if strict => {
+                            return Err(PolarsError::ComputeError(
+                                format!("could not parse '{s}' as datetime with format '{fmt}'")
+                                    .into(),
+                            ))
+                        }
+                        None => out.push(None),
+                    }
+                }
+            }
+        }
+
+        let result_tz = offset_fmt.is_some().then(|| tz.unwrap_or_else(|| "UTC".to_string()));
+        Ok(Int64Chunked::from_iter_options(name, out.into_iter()).into_datetime(tu, result_tz))
+    }
+
     /// Change the underlying [`TimeUnit`]. And update the data accordingly.
     #[must_use]
     pub fn cast_time_unit(&self, tu: TimeUnit) -> Self {
@@ -249,6 +544,78 @@ impl DatetimeChunked {
         }
     }
 
+    /// Change the underlying [`TimeUnit`], like [`cast_time_unit`][Self::cast_time_unit], but
+    /// guard widening conversions (e.g. Milliseconds -> Nanoseconds) against `i64` overflow
+    /// instead of silently wrapping. Nulls are left untouched.
+    pub fn try_cast_time_unit(&self, tu: TimeUnit) -> PolarsResult<Self> {
+        let current_unit = self.time_unit();
+        let mut out = self.clone();
+        out.set_time_unit(tu);
+
+        use TimeUnit::*;
+        let scale: i64 = match (current_unit, tu) {
+            (Nanoseconds, Microseconds) => {
+                out.0 = &self.0 / 1_000;
+                return Ok(out);
+            }
+            (Nanoseconds, Milliseconds) => {
+                out.0 = &self.0 / 1_000_000;
+                return Ok(out);
+            }
+            (Microseconds, Milliseconds) => {
+                out.0 = &self.0 / 1_000;
+                return Ok(out);
+            }
+            (Microseconds, Nanoseconds) => 1_000,
+            (Milliseconds, Nanoseconds) => 1_000_000,
+            (Milliseconds, Microseconds) => 1_000,
+            (Nanoseconds, Nanoseconds)
+            | (Microseconds, Microseconds)
+            | (Milliseconds, Milliseconds) => return Ok(out),
+        };
+
+        out.0 = self.0.try_apply(|v| {
+            v.checked_mul(scale).ok_or_else(|| {
+                PolarsError::ComputeError(
+                    format!(
+                        "timestamp {v} would overflow i64 when casting from {current_unit:?} to {tu:?}"
+                    )
+                    .into(),
+                )
+            })
+        })?;
+        Ok(out)
+    }
+
+    /// Number of complete years elapsed between each value and `reference` (e.g. an age or
+    /// tenure in whole years).
+    ///
+    /// Computed from the calendar date components rather than `(reference - value) / 365 days`,
+    /// so partial years and leap-day (Feb 29) anniversaries are handled correctly.
+    pub fn elapsed_years(&self, reference: NaiveDateTime) -> Int32Chunked {
+        let ref_date = reference.date();
+        let mut years: Int32Chunked = self
+            .as_datetime_iter()
+            .map(|opt_dt| {
+                opt_dt.map(|dt| {
+                    let date = dt.date();
+                    let mut years = ref_date.year() - date.year();
+                    if (ref_date.month(), ref_date.day()) < (date.month(), date.day()) {
+                        years -= 1;
+                    }
+                    years
+                })
+            })
+            .collect();
+        years.rename(self.name());
+        years
+    }
+
+    /// [`elapsed_years`][Self::elapsed_years] against the current wall-clock time.
+    pub fn elapsed_years_now(&self) -> Int32Chunked {
+        self.elapsed_years(chrono::Utc::now().naive_utc())
+    }
+
     /// Change the underlying [`TimeUnit`]. This does not modify the data.
     pub fn set_time_unit(&mut self, tu: TimeUnit) {
         self.2 = Some(Datetime(tu, self.time_zone().clone()))
@@ -274,6 +641,96 @@ impl DatetimeChunked {
             )),
         }
     }
+
+    /// Interpret this tz-naive array's values as local wall-clock times in `tz`, computing the
+    /// per-element UTC offset (including DST) so the underlying timestamps are corrected.
+    ///
+    /// This is semantically different from [`cast_time_zone`][Self::cast_time_zone], which only
+    /// relabels the offset used to *display* a value that is already a correct UTC-based instant.
+    /// `tz_localize` instead treats the stored i64s as local clock readings and shifts them to
+    /// the UTC instant they actually represent.
+    #[cfg(feature = "timezones")]
+    pub fn tz_localize(&self, tz: &str, ambiguous: Ambiguous) -> PolarsResult<DatetimeChunked> {
+        use chrono::{Duration, LocalResult, TimeZone as _};
+        use chrono_tz::Tz;
+
+        if let Some(from) = self.time_zone() {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "cannot tz_localize an already tz-aware array (time zone '{from}'); \
+                     use cast_time_zone to re-express an existing instant in another zone"
+                )
+                .into(),
+            ));
+        }
+        validate_time_zone(tz.to_string())?;
+        let parsed: Tz = tz.parse().map_err(|_| {
+            PolarsError::ComputeError(format!("Could not parse timezone: '{tz}'").into())
+        })?;
+
+        let conversion_f = match self.time_unit() {
+            TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+            TimeUnit::Microseconds => timestamp_us_to_datetime,
+            TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+        };
+        let to_i64 = match self.time_unit() {
+            TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+            TimeUnit::Microseconds => datetime_to_timestamp_us,
+            TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+        };
+
+        let mut out = Vec::with_capacity(self.len());
+        for opt_v in self.downcast_iter().flat_map(|arr| arr.into_iter()) {
+            match opt_v.copied() {
+                None => out.push(None),
+                Some(v) => {
+                    let ndt = conversion_f(v);
+                    let resolved_utc = match parsed.from_local_datetime(&ndt) {
+                        LocalResult::Single(dt) => dt.naive_utc(),
+                        LocalResult::Ambiguous(earliest, latest) => match ambiguous {
+                            Ambiguous::Earliest => earliest.naive_utc(),
+                            Ambiguous::Latest => latest.naive_utc(),
+                            Ambiguous::Raise => {
+                                return Err(PolarsError::ComputeError(
+                                    format!(
+                                        "datetime '{ndt}' is ambiguous in time zone '{tz}'"
+                                    )
+                                    .into(),
+                                ))
+                            }
+                        },
+                        LocalResult::None => match ambiguous {
+                            Ambiguous::Raise => {
+                                return Err(PolarsError::ComputeError(
+                                    format!(
+                                        "datetime '{ndt}' does not exist in time zone '{tz}' (falls in a DST gap)"
+                                    )
+                                    .into(),
+                                ))
+                            }
+                            _ => {
+                                // Spring-forward gap: shift forward to the first wall-clock time
+                                // that does exist in `tz`.
+                                let mut shifted = ndt;
+                                loop {
+                                    shifted += Duration::hours(1);
+                                    if let LocalResult::Single(dt) =
+                                        parsed.from_local_datetime(&shifted)
+                                    {
+                                        break dt.naive_utc();
+                                    }
+                                }
+                            }
+                        },
+                    };
+                    out.push(Some(to_i64(resolved_utc)));
+                }
+            }
+        }
+
+        let ca = Int64Chunked::from_iter_options(self.name(), out.into_iter());
+        Ok(ca.into_datetime(self.time_unit(), Some(tz.to_string())))
+    }
 }
 
 #[cfg(test)]
@@ -308,4 +765,162 @@ mod test {
             dt.cont_slice().unwrap()
         );
     }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn to_rfc3339_renders_stored_time_zone() {
+        let noon_utc =
+            NaiveDateTime::parse_from_str("2021-06-15 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let mut dt = DatetimeChunked::from_naive_datetime("a", [noon_utc], TimeUnit::Milliseconds);
+        dt.set_time_zone("America/New_York".to_string()).unwrap();
+
+        // EDT (-04:00) in June: the wall-clock digits must shift, not just the offset suffix.
+        let formatted = dt.to_rfc3339(SecondsFormat::Secs);
+        assert_eq!(formatted.get(0), Some("2021-06-15T08:00:00-04:00"));
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn strftime_renders_offset_in_stored_time_zone() {
+        let noon_utc =
+            NaiveDateTime::parse_from_str("2021-06-15 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let mut dt = DatetimeChunked::from_naive_datetime("a", [noon_utc], TimeUnit::Milliseconds);
+        dt.set_time_zone("America/New_York".to_string()).unwrap();
+
+        let formatted = dt.strftime("%Y-%m-%d %H:%M:%S %z");
+        assert_eq!(formatted.get(0), Some("2021-06-15 08:00:00 -0400"));
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn tz_localize_resolves_ambiguous_and_nonexistent_times() {
+        // Clocks fall back an hour at 2021-11-07 02:00 America/New_York, so 01:30 occurs twice.
+        let ambiguous =
+            NaiveDateTime::parse_from_str("2021-11-07 01:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let ca = DatetimeChunked::from_naive_datetime("a", [ambiguous], TimeUnit::Milliseconds);
+
+        let earliest = ca
+            .tz_localize("America/New_York", Ambiguous::Earliest)
+            .unwrap();
+        let latest = ca
+            .tz_localize("America/New_York", Ambiguous::Latest)
+            .unwrap();
+        assert_eq!(latest.get(0).unwrap() - earliest.get(0).unwrap(), 3_600_000);
+        assert!(ca
+            .tz_localize("America/New_York", Ambiguous::Raise)
+            .is_err());
+
+        // Clocks spring forward an hour at 2021-03-14 02:00 America/New_York, so 02:30 never
+        // happens.
+        let nonexistent =
+            NaiveDateTime::parse_from_str("2021-03-14 02:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let gap = DatetimeChunked::from_naive_datetime("a", [nonexistent], TimeUnit::Milliseconds);
+        assert!(gap.tz_localize("America/New_York", Ambiguous::Raise).is_err());
+        assert!(gap
+            .tz_localize("America/New_York", Ambiguous::Earliest)
+            .is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn tz_localize_rejects_already_tz_aware() {
+        let noon_utc =
+            NaiveDateTime::parse_from_str("2021-06-15 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let mut dt = DatetimeChunked::from_naive_datetime("a", [noon_utc], TimeUnit::Milliseconds);
+        dt.set_time_zone("UTC".to_string()).unwrap();
+        assert!(dt
+            .tz_localize("America/New_York", Ambiguous::Earliest)
+            .is_err());
+    }
+
+    #[test]
+    fn try_cast_time_unit_overflow() {
+        let huge = Int64Chunked::from_vec("a", vec![i64::MAX])
+            .into_datetime(TimeUnit::Milliseconds, None);
+        assert!(huge.try_cast_time_unit(TimeUnit::Nanoseconds).is_err());
+
+        let small =
+            Int64Chunked::from_vec("a", vec![1]).into_datetime(TimeUnit::Milliseconds, None);
+        assert_eq!(
+            small
+                .try_cast_time_unit(TimeUnit::Nanoseconds)
+                .unwrap()
+                .cont_slice()
+                .unwrap(),
+            &[1_000_000]
+        );
+    }
+
+    #[test]
+    fn elapsed_years_handles_leap_day_anniversary() {
+        let birth =
+            NaiveDateTime::parse_from_str("2000-02-29 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let ca = DatetimeChunked::from_naive_datetime("a", [birth], TimeUnit::Milliseconds);
+
+        let day_before = NaiveDateTime::parse_from_str("2021-02-28 00:00:00", "%Y-%m-%d %H:%M:%S")
+            .unwrap();
+        assert_eq!(ca.elapsed_years(day_before).get(0), Some(20));
+
+        let day_after = NaiveDateTime::parse_from_str("2021-03-01 00:00:00", "%Y-%m-%d %H:%M:%S")
+            .unwrap();
+        assert_eq!(ca.elapsed_years(day_after).get(0), Some(21));
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn strptime_with_offset_normalizes_to_utc_and_sets_tz() {
+        let s = Utf8Chunked::new("a", &["2021-06-15T08:00:00-04:00"]);
+        let parsed = DatetimeChunked::strptime(
+            "a",
+            &s,
+            "%Y-%m-%dT%H:%M:%S%:z",
+            TimeUnit::Milliseconds,
+            Some("America/New_York".to_string()),
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(parsed.time_zone(), &Some("America/New_York".to_string()));
+        let expected =
+            NaiveDateTime::parse_from_str("2021-06-15 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        assert_eq!(parsed.as_datetime_iter().next().unwrap(), Some(expected));
+    }
+
+    #[test]
+    fn strptime_round_trips_to_rfc3339_zulu_output() {
+        let noon_utc =
+            NaiveDateTime::parse_from_str("2021-06-15 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let dt = DatetimeChunked::from_naive_datetime("a", [noon_utc], TimeUnit::Milliseconds);
+        let formatted = dt.to_rfc3339(SecondsFormat::Secs);
+        assert_eq!(formatted.get(0), Some("2021-06-15T12:00:00Z"));
+
+        let parsed = DatetimeChunked::strptime(
+            "a",
+            &formatted,
+            "%Y-%m-%dT%H:%M:%SZ",
+            TimeUnit::Milliseconds,
+            None,
+            true,
+        )
+        .unwrap();
+        assert_eq!(parsed.as_datetime_iter().next().unwrap(), Some(noon_utc));
+    }
+
+    #[test]
+    fn strptime_bare_abbreviation_stays_tz_naive() {
+        // `%Z` alone carries no resolvable offset, so this must not take the offset-parsing
+        // fast path (which would set a spurious time zone on the result).
+        let s = Utf8Chunked::new("a", &["2021-06-15 08:00:00 EDT"]);
+        let parsed = DatetimeChunked::strptime(
+            "a",
+            &s,
+            "%Y-%m-%d %H:%M:%S %Z",
+            TimeUnit::Milliseconds,
+            Some("America/New_York".to_string()),
+            false,
+        )
+        .unwrap();
+
+        assert!(parsed.time_zone().is_none());
+    }
 }